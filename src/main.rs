@@ -8,7 +8,7 @@ use std::fs;
 use std::time::{Duration, Instant};
 
 fn human_ms(d: Duration) -> f64 {
-    (d.as_secs_f64() * 1000.0)
+    d.as_secs_f64() * 1000.0
 }
 
 fn report(name: &str, duration: Duration, bytes: usize, pages: Option<usize>, ok: bool, err: Option<String>) {
@@ -21,21 +21,169 @@ fn report(name: &str, duration: Duration, bytes: usize, pages: Option<usize>, ok
     if let Some(e) = err { println!("  Error: {}", e); }
 }
 
+// ========== Text-accuracy comparison ==========
+// Compares every backend's extracted text against a reference extraction
+// (default: `pdftotext -layout`, overridable with `--reference <backend>`)
+// so the benchmark measures correctness, not just speed.
+mod compare {
+    // Lowercase, collapse whitespace runs, and strip form-feeds so that
+    // formatting differences between backends don't masquerade as content
+    // differences.
+    pub fn normalize(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        let mut last_was_space = false;
+        for c in s.chars() {
+            if c == '\x0C' {
+                continue;
+            }
+            if c.is_whitespace() {
+                if !last_was_space {
+                    out.push(' ');
+                }
+                last_was_space = true;
+            } else {
+                for lc in c.to_lowercase() {
+                    out.push(lc);
+                }
+                last_was_space = false;
+            }
+        }
+        out.trim().to_string()
+    }
+
+    // Classic Wagner-Fischer edit distance over chars.
+    fn levenshtein(a: &[char], b: &[char]) -> usize {
+        let (la, lb) = (a.len(), b.len());
+        if la == 0 { return lb; }
+        if lb == 0 { return la; }
+        let mut prev: Vec<usize> = (0..=lb).collect();
+        let mut cur: Vec<usize> = vec![0; lb + 1];
+        for i in 1..=la {
+            cur[0] = i;
+            for j in 1..=lb {
+                let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+                cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            }
+            std::mem::swap(&mut prev, &mut cur);
+        }
+        prev[lb]
+    }
+
+    // 1 - edits/max_len, clamped to [0, 1]. Two empty strings are a perfect match.
+    pub fn levenshtein_ratio(a: &str, b: &str) -> f64 {
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+        let max_len = a_chars.len().max(b_chars.len());
+        if max_len == 0 {
+            return 1.0;
+        }
+        let edits = levenshtein(&a_chars, &b_chars);
+        (1.0 - (edits as f64 / max_len as f64)).max(0.0)
+    }
+
+    // |intersection| / |union| over whitespace-split word sets.
+    pub fn jaccard(a: &str, b: &str) -> f64 {
+        use std::collections::HashSet;
+        let set_a: HashSet<&str> = a.split_whitespace().collect();
+        let set_b: HashSet<&str> = b.split_whitespace().collect();
+        if set_a.is_empty() && set_b.is_empty() {
+            return 1.0;
+        }
+        let intersection = set_a.intersection(&set_b).count();
+        let union = set_a.union(&set_b).count();
+        if union == 0 {
+            return 1.0;
+        }
+        intersection as f64 / union as f64
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn normalize_collapses_whitespace_and_lowercases() {
+            assert_eq!(normalize("  Hello\t\tWorld\n"), "hello world");
+        }
+
+        #[test]
+        fn normalize_strips_form_feeds() {
+            assert_eq!(normalize("page one\x0Cpage two"), "page onepage two");
+        }
+
+        #[test]
+        fn levenshtein_ratio_identical_strings_is_one() {
+            assert_eq!(levenshtein_ratio("same text", "same text"), 1.0);
+        }
+
+        #[test]
+        fn levenshtein_ratio_empty_strings_is_one() {
+            assert_eq!(levenshtein_ratio("", ""), 1.0);
+        }
+
+        #[test]
+        fn levenshtein_ratio_completely_different_is_zero() {
+            assert_eq!(levenshtein_ratio("abc", "xyz"), 0.0);
+        }
+
+        #[test]
+        fn levenshtein_ratio_partial_match_is_between_zero_and_one() {
+            let ratio = levenshtein_ratio("hello world", "hello word");
+            assert!(ratio > 0.0 && ratio < 1.0);
+        }
+
+        #[test]
+        fn jaccard_identical_word_sets_is_one() {
+            assert_eq!(jaccard("a b c", "c b a"), 1.0);
+        }
+
+        #[test]
+        fn jaccard_empty_strings_is_one() {
+            assert_eq!(jaccard("", ""), 1.0);
+        }
+
+        #[test]
+        fn jaccard_disjoint_word_sets_is_zero() {
+            assert_eq!(jaccard("a b c", "x y z"), 0.0);
+        }
+
+        #[test]
+        fn jaccard_partial_overlap() {
+            // {a, b} vs {b, c}: intersection {b} (1), union {a, b, c} (3)
+            assert_eq!(jaccard("a b", "b c"), 1.0 / 3.0);
+        }
+    }
+}
+
+// ========== Document metadata ==========
+// Backends disagree on more than just text: they also decode the Info
+// dictionary / XMP fields differently (e.g. UTF-16BE title strings). This
+// struct is the common shape each capable backend fills in so the results
+// can be printed side by side.
+#[derive(Default, Clone)]
+struct DocMetadata {
+    title: Option<String>,
+    author: Option<String>,
+    subject: Option<String>,
+    keywords: Option<String>,
+    creator: Option<String>,
+    producer: Option<String>,
+    creation_date: Option<String>,
+}
+
 // ========== Backend: pdf-extract (pure Rust wrapper) ==========
 // Crate: pdf-extract (docs: crates.io / docs.rs)
 // Add to Cargo.toml: pdf-extract = "0.7"  (检查 crates.io 最新版本)
 #[cfg(feature = "pdf_extract")]
 mod pdf_extract_backend {
-    pub fn run(path: &str) -> Result<(usize, Option<usize>), String> {
+    pub fn run(path: &str) -> Result<(String, Option<usize>), String> {
         let bytes = std::fs::read(path).map_err(|e| format!("read error: {}", e))?;
         // pdf_extract provides extract_text_from_mem and extract_text_by_pages
         match pdf_extract::extract_text_from_mem(&bytes) {
             Ok(text) => {
-				//println!("pdf_extract: {}", text);
-                let len = text.as_bytes().len();
                 // naive page count by splitting on form feed or by pages API could be used
                 let pages = text.matches('\x0C').count();
-                Ok((len, if pages>0 {Some(pages)} else {None}))
+                Ok((text, if pages>0 {Some(pages)} else {None}))
             }
             Err(e) => Err(format!("pdf-extract error: {:?}", e)),
         }
@@ -50,9 +198,20 @@ mod lopdf_backend {
     use lopdf::{Document, Object, ObjectId};
     use std::collections::HashMap;
 
+    // Loads the document and decrypts it in place when it's password
+    // protected, rather than letting every caller duplicate that dance.
+    fn load(path: &str, password: Option<&str>) -> Result<Document, String> {
+        let mut doc = Document::load(path).map_err(|e| format!("lopdf load error: {}", e))?;
+        if doc.is_encrypted() {
+            let password = password.ok_or_else(|| "encrypted, password required".to_string())?;
+            doc.decrypt(password).map_err(|e| format!("lopdf decrypt error: {}", e))?;
+        }
+        Ok(doc)
+    }
+
     // A simple text extraction attempt using content streams.
-    pub fn run(path: &str) -> Result<(usize, Option<usize>), String> {
-        let doc = Document::load(path).map_err(|e| format!("lopdf load error: {}", e))?;
+    pub fn run(path: &str, password: Option<&str>) -> Result<(String, Option<usize>), String> {
+        let doc = load(path, password)?;
         // gather pages
         let mut extracted = String::new();
         let pages = doc.get_pages();
@@ -66,9 +225,82 @@ mod lopdf_backend {
                 // fallback: try to parse content stream manually (not implemented here)
             }
         }
-		//println!("lopdf: {}", extracted);
-        let len = extracted.as_bytes().len();
-        Ok((len, Some(pages.len())))
+        Ok((extracted, Some(pages.len())))
+    }
+
+    // lopdf can read the `Info` trailer dictionary directly, no higher-level
+    // metadata API needed.
+    pub fn metadata(path: &str, password: Option<&str>) -> Result<super::DocMetadata, String> {
+        let doc = load(path, password)?;
+        let info = match doc.trailer.get(b"Info").and_then(Object::as_reference) {
+            Ok(id) => doc.get_object(id).ok().and_then(|o| o.as_dict().ok()),
+            Err(_) => None,
+        };
+
+        let get = |dict: &lopdf::Dictionary, key: &[u8]| -> Option<String> {
+            dict.get(key).ok().and_then(|o| o.as_str().ok()).map(|s| {
+                String::from_utf8(s.to_vec()).unwrap_or_else(|_| String::from_utf8_lossy(s).to_string())
+            })
+        };
+
+        let mut meta = super::DocMetadata::default();
+        if let Some(dict) = info {
+            meta.title = get(dict, b"Title");
+            meta.author = get(dict, b"Author");
+            meta.subject = get(dict, b"Subject");
+            meta.keywords = get(dict, b"Keywords");
+            meta.creator = get(dict, b"Creator");
+            meta.producer = get(dict, b"Producer");
+            meta.creation_date = get(dict, b"CreationDate");
+        }
+        Ok(meta)
+    }
+}
+
+// ========== Backend: pdf-rs (pure Rust wrapper) ==========
+// Crate: pdf (pdf-rs, docs: https://docs.rs/pdf)
+// Add to Cargo.toml: pdf = "0.10"  (检查 crates.io 最新版本)
+#[cfg(feature = "pdf")]
+mod pdf_backend {
+    use pdf::content::Op;
+    use pdf::file::FileOptions;
+
+    // pdf-rs panics/errors on some malformed files (e.g. `UnexpectedPrimitive`
+    // on certain arXiv PDFs), so per-page errors are captured and the page
+    // skipped rather than aborting the whole run. A panic is not caught.
+    pub fn run(path: &str) -> Result<(String, Option<usize>), String> {
+        let file = FileOptions::cached().open(path).map_err(|e| format!("pdf-rs open error: {:?}", e))?;
+
+        let mut extracted = String::new();
+        let mut page_count = 0usize;
+        for page_result in file.pages() {
+            page_count += 1;
+            let page = match page_result {
+                Ok(p) => p,
+                Err(e) => {
+                    eprintln!("pdf-rs: skipping unreadable page {}: {:?}", page_count, e);
+                    continue;
+                }
+            };
+            let contents = match page.contents.as_ref() {
+                Some(c) => c,
+                None => continue,
+            };
+            let ops = match contents.operations(&file) {
+                Ok(ops) => ops,
+                Err(e) => {
+                    eprintln!("pdf-rs: skipping page {} with bad content stream: {:?}", page_count, e);
+                    continue;
+                }
+            };
+            for op in ops.iter() {
+                if let Op::TextDraw { text } = op {
+                    extracted.push_str(&text.to_string_lossy());
+                    extracted.push(' ');
+                }
+            }
+        }
+        Ok((extracted, Some(page_count)))
     }
 }
 
@@ -79,14 +311,33 @@ mod lopdf_backend {
 #[cfg(feature = "pdfium")]
 mod pdfium_backend {
     use pdfium_render::prelude::*;
-    pub fn run(path: &str) -> Result<(usize, Option<usize>), String> {
-        // initialize library
-		let pdfium = Pdfium::new(
-			Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path("./lib")).unwrap()
-		);
-
-        let doc = pdfium.load_pdf_from_file(path, None)
-            .map_err(|e| format!("pdfium load error: {:?}", e))?;
+
+    // Binds to the pdfium shared library, failing cleanly (instead of the
+    // panic `Pdfium::bind_to_library(...).unwrap()` would give) when it
+    // isn't found at the expected relative path.
+    pub(super) fn pdfium() -> Result<Pdfium, String> {
+        Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path("./lib"))
+            .map(Pdfium::new)
+            .map_err(|e| format!("failed to bind pdfium library: {:?}", e))
+    }
+
+    // pdfium reports a password-protected file as a load error rather than
+    // a distinct document state, so a missing password is detected by
+    // matching the error text and reported distinctly from other failures.
+    // A wrong password is reported as an ordinary load error.
+    fn load<'a>(pdfium: &'a Pdfium, path: &str, password: Option<&str>) -> Result<PdfDocument<'a>, String> {
+        pdfium.load_pdf_from_file(path, password).map_err(|e| {
+            if password.is_none() && format!("{:?}", e).to_lowercase().contains("password") {
+                "encrypted, password required".to_string()
+            } else {
+                format!("pdfium load error: {:?}", e)
+            }
+        })
+    }
+
+    pub fn run(path: &str, password: Option<&str>) -> Result<(String, Option<usize>), String> {
+        let pdfium = pdfium()?;
+        let doc = load(&pdfium, path, password)?;
         let page_count = doc.pages().len();
         let mut all = String::new();
         for i in 0..page_count {
@@ -95,8 +346,54 @@ mod pdfium_backend {
 			let text = page.text().unwrap().all(); // https://github.com/ajrcarey/pdfium-render/blob/master/examples/text_extract.rs
             all.push_str(&text);
         }
-		println!("pdfium: {}", all);
-        Ok((all.as_bytes().len(), Some(page_count.into())))
+        Ok((all, Some(page_count.into())))
+    }
+
+    // Rasterizes every page to an RGBA bitmap at `dpi` and times only the
+    // PDF engine's render path; PNG encoding only happens when `save_dir`
+    // is given, since that's a separate cost we don't want polluting the
+    // render timing by default.
+    pub fn render(path: &str, dpi: u32, save_dir: Option<&str>, password: Option<&str>) -> Result<super::render::RenderStats, String> {
+        let pdfium = pdfium()?;
+        let doc = load(&pdfium, path, password)?;
+        let page_count = doc.pages().len();
+        let config = PdfRenderConfig::new().scale_page_by_factor(dpi as f32 / 72.0);
+
+        let mut total_pixels: u64 = 0;
+        let start = std::time::Instant::now();
+        for i in 0..page_count {
+            let page = doc.pages().get(i).unwrap();
+            let bitmap = page.render_with_config(&config)
+                .map_err(|e| format!("pdfium render error on page {}: {:?}", i, e))?;
+            let (w, h) = (bitmap.width() as u64, bitmap.height() as u64);
+            total_pixels += w * h;
+            if let Some(dir) = save_dir {
+                let out_path = format!("{}/pdfium_page_{:04}.png", dir, i);
+                bitmap.as_image().save(&out_path)
+                    .map_err(|e| format!("failed to save {}: {}", out_path, e))?;
+            }
+        }
+        let total_time = start.elapsed();
+        Ok(super::render::RenderStats { total_time, pages: page_count.into(), total_pixels })
+    }
+
+    // pdfium-render exposes decoded Info-dictionary fields directly via
+    // `PdfDocument::metadata()`.
+    pub fn metadata(path: &str, password: Option<&str>) -> Result<super::DocMetadata, String> {
+        let pdfium = pdfium()?;
+        let doc = load(&pdfium, path, password)?;
+        let meta = doc.metadata();
+
+        let get = |tag: PdfDocumentMetadataTagType| meta.get(tag).map(|v| v.value().to_string());
+        Ok(super::DocMetadata {
+            title: get(PdfDocumentMetadataTagType::Title),
+            author: get(PdfDocumentMetadataTagType::Author),
+            subject: get(PdfDocumentMetadataTagType::Subject),
+            keywords: get(PdfDocumentMetadataTagType::Keywords),
+            creator: get(PdfDocumentMetadataTagType::Creator),
+            producer: get(PdfDocumentMetadataTagType::Producer),
+            creation_date: get(PdfDocumentMetadataTagType::CreationDate),
+        })
     }
 }
 
@@ -107,8 +404,23 @@ mod pdfium_backend {
 #[cfg(feature = "poppler")]
 mod poppler_backend {
     use poppler::PopplerDocument;
-    pub fn run(path: &str) -> Result<(usize, Option<usize>), String> {
-        let doc = PopplerDocument::new_from_file(path, "").map_err(|e| format!("poppler new error: {:?}", e))?;
+
+    // poppler's password argument is a plain &str rather than an Option, so
+    // a missing password on an encrypted file surfaces as a generic open
+    // error; match on the error text to report it distinctly. A wrong
+    // password is reported as an ordinary open error.
+    fn load(path: &str, password: Option<&str>) -> Result<PopplerDocument, String> {
+        PopplerDocument::new_from_file(path, password.unwrap_or("")).map_err(|e| {
+            if password.is_none() && format!("{:?}", e).to_lowercase().contains("password") {
+                "encrypted, password required".to_string()
+            } else {
+                format!("poppler new error: {:?}", e)
+            }
+        })
+    }
+
+    pub fn run(path: &str, password: Option<&str>) -> Result<(String, Option<usize>), String> {
+        let doc = load(path, password)?;
         let n = doc.get_n_pages();
         let mut all = String::new();
         for i in 0..n {
@@ -116,14 +428,170 @@ mod poppler_backend {
             let txt = page.get_text().ok_or_else(|| format!("poppler get_text {}", i))?;
             all.push_str(&txt);
         }
-        Ok((all.as_bytes().len(), Some(n)))
+        Ok((all, Some(n)))
+    }
+
+    // Same render-timing harness as the pdfium backend, but driven through
+    // poppler's cairo rendering (requires the `render` feature of poppler-rs).
+    #[cfg(feature = "render")]
+    pub fn render(path: &str, dpi: u32, save_dir: Option<&str>, password: Option<&str>) -> Result<super::render::RenderStats, String> {
+        let doc = load(path, password)?;
+        let n = doc.get_n_pages();
+        let scale = dpi as f64 / 72.0;
+
+        let mut total_pixels: u64 = 0;
+        let start = std::time::Instant::now();
+        for i in 0..n {
+            let page = doc.get_page(i).ok_or_else(|| format!("poppler get_page {}", i))?;
+            let (pw, ph) = page.get_size();
+            let w = (pw * scale).round() as i32;
+            let h = (ph * scale).round() as i32;
+            let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, w, h)
+                .map_err(|e| format!("cairo surface error: {:?}", e))?;
+            let ctx = cairo::Context::new(&surface).map_err(|e| format!("cairo context error: {:?}", e))?;
+            ctx.scale(scale, scale);
+            page.render(&ctx);
+            total_pixels += w as u64 * h as u64;
+            if let Some(dir) = save_dir {
+                let out_path = format!("{}/poppler_page_{:04}.png", dir, i);
+                let mut file = std::fs::File::create(&out_path)
+                    .map_err(|e| format!("failed to create {}: {}", out_path, e))?;
+                surface.write_to_png(&mut file)
+                    .map_err(|e| format!("failed to save {}: {}", out_path, e))?;
+            }
+        }
+        let total_time = start.elapsed();
+        Ok(super::render::RenderStats { total_time, pages: n, total_pixels })
+    }
+
+    // poppler exposes each Info field through its own getter method rather
+    // than a single dictionary.
+    pub fn metadata(path: &str, password: Option<&str>) -> Result<super::DocMetadata, String> {
+        let doc = load(path, password)?;
+        Ok(super::DocMetadata {
+            title: doc.get_title(),
+            author: doc.get_author(),
+            subject: doc.get_subject(),
+            keywords: doc.get_keywords(),
+            creator: doc.get_creator(),
+            producer: doc.get_producer(),
+            creation_date: doc.get_creation_date().map(|d| d.to_string()),
+        })
+    }
+}
+
+// ========== Render-benchmark mode ==========
+// Times page rasterization instead of text extraction. Supported by the
+// pdfium and poppler backends (behind their existing feature cfgs, plus
+// `render` for poppler's cairo rendering); both report through this same
+// struct so the numbers are comparable. Gated the same way, so it isn't
+// dead code when neither render-capable backend is compiled in.
+#[cfg(any(feature = "pdfium", all(feature = "poppler", feature = "render")))]
+mod render {
+    use std::time::Duration;
+
+    pub struct RenderStats {
+        pub total_time: Duration,
+        pub pages: usize,
+        pub total_pixels: u64,
+    }
+
+    pub fn report(name: &str, stats: &RenderStats) {
+        println!("---");
+        println!("Backend: {} (render)", name);
+        println!("  Total render time: {:.2} ms", super::human_ms(stats.total_time));
+        if stats.pages > 0 {
+            println!("  Avg per page: {:.2} ms", super::human_ms(stats.total_time) / stats.pages as f64);
+        }
+        println!("  Pages: {}", stats.pages);
+        println!("  Pixels rendered: {}", stats.total_pixels);
+    }
+}
+
+// ========== Backend: OCR fallback (for scanned/image-only PDFs) ==========
+// Crates: tesseract / leptess (Tesseract bindings) + pdfium-render for rasterization
+// Add to Cargo.toml: leptess = "0.14"  (检查最新; requires a system Tesseract install)
+// Requires the `pdfium` feature, since OCR rasterizes pages via pdfium first.
+// Every backend above silently returns near-zero bytes on scanned PDFs with
+// no text layer; this one renders the page and recovers text with Tesseract.
+// To avoid wasting OCR cycles on pages that already have a text layer, a
+// cheap text-extraction attempt gates OCR: only pages whose extracted
+// length falls below `MIN_CHARS_PER_PAGE` get rasterized and OCR'd.
+#[cfg(all(feature = "ocr", feature = "pdfium"))]
+mod ocr_backend {
+    use pdfium_render::prelude::*;
+    use std::time::{Duration, Instant};
+
+    const MIN_CHARS_PER_PAGE: usize = 10;
+
+    pub struct OcrStats {
+        pub text: String,
+        pub pages: usize,
+        pub ocr_pages: usize,
+        pub render_time: Duration,
+        pub ocr_time: Duration,
+    }
+
+    pub fn run(path: &str, dpi: u32, password: Option<&str>) -> Result<OcrStats, String> {
+        let pdfium = super::pdfium_backend::pdfium()?;
+        let doc = pdfium.load_pdf_from_file(path, password).map_err(|e| {
+            if password.is_none() && format!("{:?}", e).to_lowercase().contains("password") {
+                "encrypted, password required".to_string()
+            } else {
+                format!("pdfium load error: {:?}", e)
+            }
+        })?;
+        let page_count = doc.pages().len();
+        let config = PdfRenderConfig::new().scale_page_by_factor(dpi as f32 / 72.0);
+
+        let mut all = String::new();
+        let mut render_time = Duration::ZERO;
+        let mut ocr_time = Duration::ZERO;
+        let mut ocr_pages = 0usize;
+
+        for i in 0..page_count {
+            let page = doc.pages().get(i).unwrap();
+
+            // Cheap gate: pages that already extract a reasonable amount of
+            // text have a real text layer, so skip the expensive OCR path.
+            let cheap_text = page.text().map(|t| t.all()).unwrap_or_default();
+            if cheap_text.trim().chars().count() >= MIN_CHARS_PER_PAGE {
+                all.push_str(&cheap_text);
+                continue;
+            }
+
+            let render_start = Instant::now();
+            let bitmap = page.render_with_config(&config)
+                .map_err(|e| format!("pdfium render error on page {}: {:?}", i, e))?;
+            render_time += render_start.elapsed();
+
+            let ocr_start = Instant::now();
+            let text = ocr_bitmap(&bitmap)?;
+            ocr_time += ocr_start.elapsed();
+            ocr_pages += 1;
+
+            all.push_str(&text);
+            all.push(' ');
+        }
+
+        Ok(OcrStats { text: all, pages: page_count.into(), ocr_pages, render_time, ocr_time })
+    }
+
+    fn ocr_bitmap(bitmap: &PdfBitmap) -> Result<String, String> {
+        use leptess::LepTess;
+        let image = bitmap.as_image().to_rgb8();
+        let (width, height) = image.dimensions();
+        let mut lt = LepTess::new(None, "eng").map_err(|e| format!("tesseract init error: {:?}", e))?;
+        lt.set_image_from_mem(image.as_raw(), width, height)
+            .map_err(|e| format!("tesseract set_image error: {:?}", e))?;
+        lt.get_utf8_text().map_err(|e| format!("tesseract ocr error: {:?}", e))
     }
 }
 
 // ========== Fallback: generic CLI pdftotext (if available on system) ==========
 mod cli_pdftotext {
     use std::process::Command;
-    pub fn run(path: &str) -> Result<(usize, Option<usize>), String> {
+    pub fn run(path: &str) -> Result<(String, Option<usize>), String> {
         // requires `pdftotext` (poppler-utils) installed
         let out = Command::new("pdftotext")
             .arg("-q") // quiet
@@ -136,18 +604,426 @@ mod cli_pdftotext {
             return Err(format!("pdftotext failed: {}", out.status));
         }
         let txt = String::from_utf8_lossy(&out.stdout).to_string();
-		//println!("pdftotext: {}", txt);
-        Ok((txt.as_bytes().len(), None))
+        Ok((txt, None))
+    }
+}
+
+// ========== Machine-readable output ==========
+// Collects every backend's result into one struct so a run can be emitted
+// as JSON/CSV for CI dashboards and spreadsheets, or as an aligned table
+// for an interactive terminal run, instead of only free-form text.
+// Add to Cargo.toml: serde = { version = "1", features = ["derive"] },
+// serde_json = "1", csv = "1", comfy-table = "7"  (检查最新版本)
+mod output {
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    pub struct BackendResult {
+        pub name: String,
+        pub time_ms: f64,
+        pub bytes: usize,
+        pub pages: Option<usize>,
+        pub ok: bool,
+        pub err: Option<String>,
+        pub similarity: Option<Similarity>,
+    }
+
+    #[derive(Serialize, Clone, Copy)]
+    pub struct Similarity {
+        pub levenshtein_ratio: f64,
+        pub jaccard: f64,
+    }
+
+    pub fn print_json(results: &[BackendResult]) {
+        match serde_json::to_string_pretty(results) {
+            Ok(s) => println!("{}", s),
+            Err(e) => eprintln!("failed to serialize results as JSON: {}", e),
+        }
+    }
+
+    pub fn print_csv(results: &[BackendResult]) {
+        let mut writer = csv::Writer::from_writer(std::io::stdout());
+        for r in results {
+            let record = [
+                r.name.clone(),
+                format!("{:.2}", r.time_ms),
+                r.bytes.to_string(),
+                r.pages.map(|p| p.to_string()).unwrap_or_default(),
+                r.ok.to_string(),
+                r.err.clone().unwrap_or_default(),
+                r.similarity.map(|s| format!("{:.4}", s.levenshtein_ratio)).unwrap_or_default(),
+                r.similarity.map(|s| format!("{:.4}", s.jaccard)).unwrap_or_default(),
+            ];
+            if let Err(e) = writer.write_record(&record) {
+                eprintln!("failed to write CSV record: {}", e);
+            }
+        }
+        if let Err(e) = writer.flush() {
+            eprintln!("failed to flush CSV output: {}", e);
+        }
+    }
+
+    pub fn print_table(results: &[BackendResult]) {
+        use comfy_table::{Table, presets::UTF8_FULL};
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            "Backend", "Time (ms)", "Bytes", "Pages", "OK", "Levenshtein", "Jaccard", "Error",
+        ]);
+        for r in results {
+            table.add_row(vec![
+                r.name.clone(),
+                format!("{:.2}", r.time_ms),
+                r.bytes.to_string(),
+                r.pages.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string()),
+                r.ok.to_string(),
+                r.similarity.map(|s| format!("{:.4}", s.levenshtein_ratio)).unwrap_or_else(|| "-".to_string()),
+                r.similarity.map(|s| format!("{:.4}", s.jaccard)).unwrap_or_else(|| "-".to_string()),
+                r.err.clone().unwrap_or_default(),
+            ]);
+        }
+        println!("{}", table);
+    }
+
+    // One backend's aggregate stats across a whole batch run: the statistics
+    // `mod batch` computes from its own `Aggregate`, serializable for
+    // --output json/csv/table.
+    #[derive(Serialize)]
+    pub struct BatchAggregateResult {
+        pub name: String,
+        pub total_time_ms: f64,
+        pub mean_time_ms: f64,
+        pub median_time_ms: f64,
+        pub success_rate: f64,
+        pub successes: usize,
+        pub attempts: usize,
+        pub total_bytes: usize,
+    }
+
+    pub fn print_batch_json(results: &[BatchAggregateResult]) {
+        match serde_json::to_string_pretty(results) {
+            Ok(s) => println!("{}", s),
+            Err(e) => eprintln!("failed to serialize batch results as JSON: {}", e),
+        }
+    }
+
+    pub fn print_batch_csv(results: &[BatchAggregateResult]) {
+        let mut writer = csv::Writer::from_writer(std::io::stdout());
+        for r in results {
+            let record = [
+                r.name.clone(),
+                format!("{:.2}", r.total_time_ms),
+                format!("{:.2}", r.mean_time_ms),
+                format!("{:.2}", r.median_time_ms),
+                format!("{:.1}", r.success_rate),
+                r.successes.to_string(),
+                r.attempts.to_string(),
+                r.total_bytes.to_string(),
+            ];
+            if let Err(e) = writer.write_record(&record) {
+                eprintln!("failed to write CSV record: {}", e);
+            }
+        }
+        if let Err(e) = writer.flush() {
+            eprintln!("failed to flush CSV output: {}", e);
+        }
+    }
+
+    pub fn print_batch_table(results: &[BatchAggregateResult]) {
+        use comfy_table::{Table, presets::UTF8_FULL};
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            "Backend", "Total (ms)", "Mean (ms)", "Median (ms)", "Success rate", "Successes", "Attempts", "Total bytes",
+        ]);
+        for r in results {
+            table.add_row(vec![
+                r.name.clone(),
+                format!("{:.2}", r.total_time_ms),
+                format!("{:.2}", r.mean_time_ms),
+                format!("{:.2}", r.median_time_ms),
+                format!("{:.1}%", r.success_rate),
+                r.successes.to_string(),
+                r.attempts.to_string(),
+                r.total_bytes.to_string(),
+            ]);
+        }
+        println!("{}", table);
+    }
+}
+
+// One backend's result, kept around after `report()` so the comparison
+// phase can score it against the reference text and the output phase can
+// serialize it.
+struct BackendRun {
+    name: &'static str,
+    time_ms: f64,
+    bytes: usize,
+    pages: Option<usize>,
+    ok: bool,
+    err: Option<String>,
+    text: Option<String>,
+}
+
+// ========== Batch mode ==========
+// Benchmarks every PDF in a directory or glob in parallel (one file's
+// numbers don't tell you whether a backend is robust; a 500-file corpus
+// does), then aggregates per-backend timing and success rate.
+// Add to Cargo.toml: rayon = "1", glob = "0.3"
+mod batch {
+    use super::{run_backends, BackendRun};
+    use rayon::prelude::*;
+
+    // Returns the list of PDF paths to benchmark if `path` looks like a
+    // directory or a glob pattern, or `None` if it should be treated as a
+    // single file.
+    pub fn expand(path: &str) -> Option<Vec<String>> {
+        let is_glob = path.contains('*') || path.contains('?') || path.contains('[');
+        let is_dir = std::fs::metadata(path).map(|m| m.is_dir()).unwrap_or(false);
+        if !is_glob && !is_dir {
+            return None;
+        }
+
+        let pattern = if is_dir {
+            format!("{}/**/*.pdf", path.trim_end_matches('/'))
+        } else {
+            path.to_string()
+        };
+
+        let paths: Vec<String> = glob::glob(&pattern)
+            .unwrap_or_else(|e| {
+                eprintln!("invalid glob pattern '{}': {}", pattern, e);
+                std::process::exit(2);
+            })
+            .filter_map(|entry| entry.ok())
+            .filter(|p| p.is_file())
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+
+        if paths.is_empty() {
+            eprintln!("no PDF files matched '{}'", pattern);
+            std::process::exit(1);
+        }
+        Some(paths)
+    }
+
+    struct Aggregate {
+        name: &'static str,
+        times_ms: Vec<f64>,
+        successes: usize,
+        attempts: usize,
+        total_bytes: usize,
+    }
+
+    fn median(values: &mut [f64]) -> f64 {
+        if values.is_empty() {
+            return 0.0;
+        }
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mid = values.len() / 2;
+        if values.len().is_multiple_of(2) {
+            (values[mid - 1] + values[mid]) / 2.0
+        } else {
+            values[mid]
+        }
+    }
+
+    // Per-backend timing stays sequential within each file (so one file's
+    // timings aren't skewed by contention), but the corpus itself runs in
+    // parallel across files via rayon.
+    //
+    // `output_mode` follows the same {text,json,csv,table} contract as the
+    // single-file path: text mode prints the human-readable report below,
+    // the other modes emit one serialized aggregate record per backend via
+    // `super::output` instead, so batch runs can feed a CI dashboard.
+    pub fn run(paths: &[String], ocr_dpi: u32, password: Option<&str>, output_mode: &str) {
+        if output_mode == "text" {
+            println!("Batch benchmark: {} files", paths.len());
+        }
+
+        let per_file: Vec<Vec<BackendRun>> = paths
+            .into_par_iter()
+            .map(|path| run_backends(path, ocr_dpi, "batch", password))
+            .collect();
+
+        let mut aggregates: Vec<Aggregate> = Vec::new();
+        for runs in &per_file {
+            for run in runs {
+                let agg = match aggregates.iter_mut().find(|a| a.name == run.name) {
+                    Some(a) => a,
+                    None => {
+                        aggregates.push(Aggregate {
+                            name: run.name,
+                            times_ms: Vec::new(),
+                            successes: 0,
+                            attempts: 0,
+                            total_bytes: 0,
+                        });
+                        aggregates.last_mut().unwrap()
+                    }
+                };
+                agg.attempts += 1;
+                agg.times_ms.push(run.time_ms);
+                if run.ok {
+                    agg.successes += 1;
+                    agg.total_bytes += run.bytes;
+                }
+            }
+        }
+
+        if output_mode != "text" {
+            let results: Vec<super::output::BatchAggregateResult> = aggregates.into_iter().map(|mut agg| {
+                let total: f64 = agg.times_ms.iter().sum();
+                let mean = total / agg.times_ms.len() as f64;
+                let med = median(&mut agg.times_ms);
+                let success_rate = 100.0 * agg.successes as f64 / agg.attempts as f64;
+                super::output::BatchAggregateResult {
+                    name: agg.name.to_string(),
+                    total_time_ms: total,
+                    mean_time_ms: mean,
+                    median_time_ms: med,
+                    success_rate,
+                    successes: agg.successes,
+                    attempts: agg.attempts,
+                    total_bytes: agg.total_bytes,
+                }
+            }).collect();
+
+            match output_mode {
+                "json" => super::output::print_batch_json(&results),
+                "csv" => super::output::print_batch_csv(&results),
+                "table" => super::output::print_batch_table(&results),
+                _ => unreachable!("validated during arg parsing"),
+            }
+            return;
+        }
+
+        println!("===");
+        println!("Aggregate results across {} files", paths.len());
+        for agg in &mut aggregates {
+            let total: f64 = agg.times_ms.iter().sum();
+            let mean = total / agg.times_ms.len() as f64;
+            let med = median(&mut agg.times_ms);
+            let success_rate = 100.0 * agg.successes as f64 / agg.attempts as f64;
+            println!("---");
+            println!("Backend: {}", agg.name);
+            println!("  Total time: {:.2} ms", total);
+            println!("  Mean time: {:.2} ms", mean);
+            println!("  Median time: {:.2} ms", med);
+            println!("  Success rate: {:.1}% ({}/{})", success_rate, agg.successes, agg.attempts);
+            println!("  Total bytes extracted: {}", agg.total_bytes);
+        }
+        println!("Done.");
     }
 }
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        eprintln!("Usage: {} /path/to/file.pdf", args[0]);
+
+    // `--reference <name>` picks which backend's output the others are
+    // scored against; defaults to pdftotext since it's always compiled in.
+    let mut reference_name = "pdftotext (cli)".to_string();
+    // `--render` switches to the rasterization benchmark; `--render-dpi`
+    // and `--render-save <dir>` configure it.
+    let mut render_mode = false;
+    let mut render_dpi: u32 = 150;
+    let mut render_save: Option<String> = None;
+    // `--ocr-dpi` controls the rasterization DPI fed to Tesseract.
+    let mut ocr_dpi: u32 = 300;
+    // `--output {text,json,csv,table}` picks how results are emitted.
+    let mut output_mode = "text".to_string();
+    // `--password <pw>` unlocks encrypted PDFs; falls back to the
+    // PDFBENCH_PASSWORD env var so it doesn't need to appear on the
+    // command line (and in shell history) in CI/batch setups.
+    let mut password: Option<String> = env::var("PDFBENCH_PASSWORD").ok();
+    let mut positional: Vec<String> = Vec::new();
+    let mut i = 1;
+    while i < args.len() {
+        if args[i] == "--reference" {
+            i += 1;
+            if i >= args.len() {
+                eprintln!("--reference requires an argument");
+                std::process::exit(2);
+            }
+            reference_name = args[i].clone();
+        } else if args[i] == "--render" {
+            render_mode = true;
+        } else if args[i] == "--render-dpi" {
+            i += 1;
+            if i >= args.len() {
+                eprintln!("--render-dpi requires an argument");
+                std::process::exit(2);
+            }
+            render_dpi = args[i].parse::<u32>().ok().filter(|&d| d > 0).unwrap_or_else(|| {
+                eprintln!("--render-dpi must be a positive integer");
+                std::process::exit(2);
+            });
+        } else if args[i] == "--render-save" {
+            i += 1;
+            if i >= args.len() {
+                eprintln!("--render-save requires a directory argument");
+                std::process::exit(2);
+            }
+            render_save = Some(args[i].clone());
+        } else if args[i] == "--ocr-dpi" {
+            i += 1;
+            if i >= args.len() {
+                eprintln!("--ocr-dpi requires an argument");
+                std::process::exit(2);
+            }
+            ocr_dpi = args[i].parse::<u32>().ok().filter(|&d| d > 0).unwrap_or_else(|| {
+                eprintln!("--ocr-dpi must be a positive integer");
+                std::process::exit(2);
+            });
+        } else if args[i] == "--output" {
+            i += 1;
+            if i >= args.len() {
+                eprintln!("--output requires an argument");
+                std::process::exit(2);
+            }
+            output_mode = args[i].clone();
+            if !["text", "json", "csv", "table"].contains(&output_mode.as_str()) {
+                eprintln!("--output must be one of: text, json, csv, table");
+                std::process::exit(2);
+            }
+        } else if args[i] == "--password" {
+            i += 1;
+            if i >= args.len() {
+                eprintln!("--password requires an argument");
+                std::process::exit(2);
+            }
+            password = Some(args[i].clone());
+        } else {
+            positional.push(args[i].clone());
+        }
+        i += 1;
+    }
+
+    if positional.len() != 1 {
+        eprintln!("Usage: {} [--reference <backend>] [--output {{text,json,csv,table}}] [--render [--render-dpi <dpi>] [--render-save <dir>]] [--ocr-dpi <dpi>] [--password <pw>] <file.pdf | dir | glob>", args[0]);
         std::process::exit(2);
     }
-    let path = &args[1];
+    let path = &positional[0];
+    let password = password.as_deref();
+
+    if let Some(dir) = &render_save {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create --render-save dir {}: {}", dir, e);
+            std::process::exit(1);
+        }
+    }
+
+    // A directory or a glob pattern switches to batch mode: benchmark every
+    // matching PDF in parallel and report corpus-wide aggregates instead of
+    // a single file's numbers. Rendering is per-file only; rather than
+    // silently falling back to text extraction, say so.
+    if let Some(paths) = batch::expand(path) {
+        if render_mode {
+            eprintln!("--render is not supported in batch mode; ignoring and running the text-extraction benchmark instead.");
+        }
+        batch::run(&paths, ocr_dpi, password, &output_mode);
+        return;
+    }
 
     // read file quick sanity
     if let Err(e) = fs::metadata(path) {
@@ -155,8 +1031,92 @@ fn main() {
         std::process::exit(1);
     }
 
-    println!("PDF extraction benchmark for: {}", path);
-    println!("Backends attempted (features):");
+    if render_mode {
+        run_render_mode(path, render_dpi, render_save.as_deref(), password);
+        return;
+    }
+
+    if output_mode == "text" {
+        println!("PDF extraction benchmark for: {}", path);
+        println!("Backends attempted (features):");
+    }
+
+    let runs = run_backends(path, ocr_dpi, &output_mode, password);
+
+    // Accuracy comparison against the reference backend's text.
+    let reference_norm = runs.iter()
+        .find(|r| r.name == reference_name)
+        .and_then(|r| r.text.as_ref())
+        .map(|reference| compare::normalize(reference));
+
+    let mut similarities: std::collections::HashMap<&'static str, output::Similarity> = std::collections::HashMap::new();
+    if let Some(reference_norm) = &reference_norm {
+        for run in &runs {
+            if run.name == reference_name {
+                continue;
+            }
+            if let Some(text) = &run.text {
+                let norm = compare::normalize(text);
+                let levenshtein_ratio = compare::levenshtein_ratio(reference_norm, &norm);
+                let jaccard = compare::jaccard(reference_norm, &norm);
+                similarities.insert(run.name, output::Similarity { levenshtein_ratio, jaccard });
+            }
+        }
+    }
+
+    if output_mode == "text" {
+        match &reference_norm {
+            Some(_) => {
+                println!("===");
+                println!("Text-accuracy comparison (reference: {})", reference_name);
+                for run in &runs {
+                    if run.name == reference_name {
+                        continue;
+                    }
+                    match similarities.get(run.name) {
+                        Some(s) => println!("  {}: levenshtein_ratio={:.4} jaccard={:.4}", run.name, s.levenshtein_ratio, s.jaccard),
+                        None => println!("  {}: (no text, skipped)", run.name),
+                    }
+                }
+            }
+            None => {
+                println!("===");
+                println!("Text-accuracy comparison skipped: reference backend '{}' produced no text", reference_name);
+            }
+        }
+        print_metadata_comparison(path, password);
+        println!("Done.");
+        return;
+    }
+
+    let results: Vec<output::BackendResult> = runs.into_iter().map(|run| {
+        let similarity = similarities.get(run.name).copied();
+        output::BackendResult {
+            name: run.name.to_string(),
+            time_ms: run.time_ms,
+            bytes: run.bytes,
+            pages: run.pages,
+            ok: run.ok,
+            err: run.err,
+            similarity,
+        }
+    }).collect();
+
+    match output_mode.as_str() {
+        "json" => output::print_json(&results),
+        "csv" => output::print_csv(&results),
+        "table" => output::print_table(&results),
+        _ => unreachable!("validated during arg parsing"),
+    }
+}
+
+// `ocr_dpi` and `password` are only read inside the `ocr`/`lopdf`/`pdfium`/
+// `poppler` feature-gated blocks below, so a build with none of them
+// compiled in leaves both unused; allow that instead of chasing feature
+// combinations with cfg'd parameter names.
+#[allow(unused_variables)]
+fn run_backends(path: &str, ocr_dpi: u32, output_mode: &str, password: Option<&str>) -> Vec<BackendRun> {
+    let mut runs: Vec<BackendRun> = Vec::new();
 
     // Attempt pdf-extract if compiled
     #[cfg(feature = "pdf_extract")]
@@ -165,72 +1125,253 @@ fn main() {
         let res = pdf_extract_backend::run(path);
         let dur = start.elapsed();
         match res {
-            Ok((bytes, pages)) => report("pdf-extract", dur, bytes, pages, true, None),
-            Err(e) => report("pdf-extract", dur, 0, None, false, Some(e)),
+            Ok((text, pages)) => {
+                let bytes = text.len();
+                if output_mode == "text" { report("pdf-extract", dur, bytes, pages, true, None); }
+                runs.push(BackendRun { name: "pdf-extract", time_ms: human_ms(dur), bytes, pages, ok: true, err: None, text: Some(text) });
+            }
+            Err(e) => {
+                if output_mode == "text" { report("pdf-extract", dur, 0, None, false, Some(e.clone())); }
+                runs.push(BackendRun { name: "pdf-extract", time_ms: human_ms(dur), bytes: 0, pages: None, ok: false, err: Some(e), text: None });
+            }
         }
     }
     #[cfg(not(feature = "pdf_extract"))]
     {
-        println!("  - pdf-extract: (disabled at compile time)");
+        if output_mode == "text" { println!("  - pdf-extract: (disabled at compile time)"); }
     }
 
     // lopdf
     #[cfg(feature = "lopdf")]
     {
         let start = Instant::now();
-        let res = lopdf_backend::run(path);
+        let res = lopdf_backend::run(path, password);
         let dur = start.elapsed();
         match res {
-            Ok((bytes, pages)) => report("lopdf", dur, bytes, pages, true, None),
-            Err(e) => report("lopdf", dur, 0, None, false, Some(e)),
+            Ok((text, pages)) => {
+                let bytes = text.len();
+                if output_mode == "text" { report("lopdf", dur, bytes, pages, true, None); }
+                runs.push(BackendRun { name: "lopdf", time_ms: human_ms(dur), bytes, pages, ok: true, err: None, text: Some(text) });
+            }
+            Err(e) => {
+                if output_mode == "text" { report("lopdf", dur, 0, None, false, Some(e.clone())); }
+                runs.push(BackendRun { name: "lopdf", time_ms: human_ms(dur), bytes: 0, pages: None, ok: false, err: Some(e), text: None });
+            }
         }
     }
 
     #[cfg(not(feature = "lopdf"))]
     {
-        println!("  - lopdf: (disabled at compile time)");
+        if output_mode == "text" { println!("  - lopdf: (disabled at compile time)"); }
+    }
+
+    // pdf-rs
+    #[cfg(feature = "pdf")]
+    {
+        let start = Instant::now();
+        let res = pdf_backend::run(path);
+        let dur = start.elapsed();
+        match res {
+            Ok((text, pages)) => {
+                let bytes = text.len();
+                if output_mode == "text" { report("pdf-rs", dur, bytes, pages, true, None); }
+                runs.push(BackendRun { name: "pdf-rs", time_ms: human_ms(dur), bytes, pages, ok: true, err: None, text: Some(text) });
+            }
+            Err(e) => {
+                if output_mode == "text" { report("pdf-rs", dur, 0, None, false, Some(e.clone())); }
+                runs.push(BackendRun { name: "pdf-rs", time_ms: human_ms(dur), bytes: 0, pages: None, ok: false, err: Some(e), text: None });
+            }
+        }
+    }
+    #[cfg(not(feature = "pdf"))]
+    {
+        if output_mode == "text" { println!("  - pdf-rs: (disabled at compile time)"); }
     }
 
     // pdfium
     #[cfg(feature = "pdfium")]
     {
         let start = Instant::now();
-        let res = pdfium_backend::run(path);
+        let res = pdfium_backend::run(path, password);
         let dur = start.elapsed();
         match res {
-            Ok((bytes, pages)) => report("pdfium-render", dur, bytes, pages, true, None),
-            Err(e) => report("pdfium-render", dur, 0, None, false, Some(e)),
+            Ok((text, pages)) => {
+                let bytes = text.len();
+                if output_mode == "text" { report("pdfium-render", dur, bytes, pages, true, None); }
+                runs.push(BackendRun { name: "pdfium-render", time_ms: human_ms(dur), bytes, pages, ok: true, err: None, text: Some(text) });
+            }
+            Err(e) => {
+                if output_mode == "text" { report("pdfium-render", dur, 0, None, false, Some(e.clone())); }
+                runs.push(BackendRun { name: "pdfium-render", time_ms: human_ms(dur), bytes: 0, pages: None, ok: false, err: Some(e), text: None });
+            }
         }
     }
     #[cfg(not(feature = "pdfium"))]
     {
-        println!("  - pdfium-render: (disabled at compile time)");
+        if output_mode == "text" { println!("  - pdfium-render: (disabled at compile time)"); }
     }
 
     // poppler
     #[cfg(feature = "poppler")]
     {
         let start = Instant::now();
-        let res = poppler_backend::run(path);
+        let res = poppler_backend::run(path, password);
         let dur = start.elapsed();
         match res {
-            Ok((bytes, pages)) => report("poppler-rs", dur, bytes, pages, true, None),
-            Err(e) => report("poppler-rs", dur, 0, None, false, Some(e)),
+            Ok((text, pages)) => {
+                let bytes = text.len();
+                if output_mode == "text" { report("poppler-rs", dur, bytes, pages, true, None); }
+                runs.push(BackendRun { name: "poppler-rs", time_ms: human_ms(dur), bytes, pages, ok: true, err: None, text: Some(text) });
+            }
+            Err(e) => {
+                if output_mode == "text" { report("poppler-rs", dur, 0, None, false, Some(e.clone())); }
+                runs.push(BackendRun { name: "poppler-rs", time_ms: human_ms(dur), bytes: 0, pages: None, ok: false, err: Some(e), text: None });
+            }
         }
     }
     #[cfg(not(feature = "poppler"))]
     {
-        println!("  - poppler-rs: (disabled at compile time)");
+        if output_mode == "text" { println!("  - poppler-rs: (disabled at compile time)"); }
     }
 
     // CLI pdftotext fallback
     {
         let start = Instant::now();
         match cli_pdftotext::run(path) {
-            Ok((bytes, _)) => report("pdftotext (cli)", start.elapsed(), bytes, None, true, None),
-            Err(e) => report("pdftotext (cli)", start.elapsed(), 0, None, false, Some(e)),
+            Ok((text, _)) => {
+                let dur = start.elapsed();
+                let bytes = text.len();
+                if output_mode == "text" { report("pdftotext (cli)", dur, bytes, None, true, None); }
+                runs.push(BackendRun { name: "pdftotext (cli)", time_ms: human_ms(dur), bytes, pages: None, ok: true, err: None, text: Some(text) });
+            }
+            Err(e) => {
+                let dur = start.elapsed();
+                if output_mode == "text" { report("pdftotext (cli)", dur, 0, None, false, Some(e.clone())); }
+                runs.push(BackendRun { name: "pdftotext (cli)", time_ms: human_ms(dur), bytes: 0, pages: None, ok: false, err: Some(e), text: None });
+            }
+        }
+    }
+
+    // OCR fallback, for the scanned/image-only PDFs that every text backend
+    // above silently returns near-zero bytes for.
+    #[cfg(all(feature = "ocr", feature = "pdfium"))]
+    {
+        match ocr_backend::run(path, ocr_dpi, password) {
+            Ok(stats) => {
+                let bytes = stats.text.len();
+                let time_ms = human_ms(stats.render_time) + human_ms(stats.ocr_time);
+                if output_mode == "text" {
+                    println!("---");
+                    println!("Backend: ocr (tesseract)");
+                    println!("  Render time: {:.2} ms", human_ms(stats.render_time));
+                    println!("  OCR time: {:.2} ms", human_ms(stats.ocr_time));
+                    println!("  Extracted bytes: {}", bytes);
+                    println!("  Pages: {}", stats.pages);
+                    println!("  Pages OCR'd: {}", stats.ocr_pages);
+                    println!("  Success: true");
+                }
+                runs.push(BackendRun { name: "ocr (tesseract)", time_ms, bytes, pages: Some(stats.pages), ok: true, err: None, text: Some(stats.text) });
+            }
+            Err(e) => {
+                if output_mode == "text" { report("ocr (tesseract)", Duration::ZERO, 0, None, false, Some(e.clone())); }
+                runs.push(BackendRun { name: "ocr (tesseract)", time_ms: 0.0, bytes: 0, pages: None, ok: false, err: Some(e), text: None });
+            }
         }
     }
+    #[cfg(not(all(feature = "ocr", feature = "pdfium")))]
+    {
+        if output_mode == "text" { println!("  - ocr (tesseract): (disabled at compile time)"); }
+    }
+
+    runs
+}
+
+// One metadata field's display name plus the getter that pulls it out of a
+// decoded `DocMetadata`, so the comparison loop below can iterate fields
+// without a type this unwieldy showing up inline.
+type MetadataField = (&'static str, fn(&DocMetadata) -> &Option<String>);
+
+// Prints a side-by-side comparison of each capable backend's decoded Info
+// fields, so mismatches (e.g. a mangled UTF-16BE title) are easy to spot.
+//
+// `path`/`password` are only read inside the `lopdf`/`pdfium`/`poppler`
+// feature-gated pushes below, and `entries` only needs to be mutable when
+// at least one of them is compiled in.
+#[allow(unused_variables, unused_mut)]
+fn print_metadata_comparison(path: &str, password: Option<&str>) {
+    let mut entries: Vec<(&'static str, Result<DocMetadata, String>)> = Vec::new();
+
+    #[cfg(feature = "lopdf")]
+    entries.push(("lopdf", lopdf_backend::metadata(path, password)));
+
+    #[cfg(feature = "pdfium")]
+    entries.push(("pdfium-render", pdfium_backend::metadata(path, password)));
+
+    #[cfg(feature = "poppler")]
+    entries.push(("poppler-rs", poppler_backend::metadata(path, password)));
+
+    if entries.is_empty() {
+        return;
+    }
+
+    println!("===");
+    println!("Document metadata comparison");
+    let fields: [MetadataField; 7] = [
+        ("Title", |m| &m.title),
+        ("Author", |m| &m.author),
+        ("Subject", |m| &m.subject),
+        ("Keywords", |m| &m.keywords),
+        ("Creator", |m| &m.creator),
+        ("Producer", |m| &m.producer),
+        ("CreationDate", |m| &m.creation_date),
+    ];
+    for (field_name, getter) in fields {
+        println!("  {}:", field_name);
+        for (name, result) in &entries {
+            match result {
+                Ok(meta) => {
+                    let value = getter(meta).as_deref().unwrap_or("(none)");
+                    println!("    {}: {}", name, value);
+                }
+                Err(e) => println!("    {}: (error: {})", name, e),
+            }
+        }
+    }
+}
+
+// `password` is only read inside the `pdfium`/`poppler`+`render`
+// feature-gated blocks below, and `any_backend` only needs to be mutable
+// when at least one of them is compiled in.
+#[allow(unused_variables, unused_mut)]
+fn run_render_mode(path: &str, dpi: u32, save_dir: Option<&str>, password: Option<&str>) {
+    println!("PDF render benchmark for: {} (dpi={})", path, dpi);
+    if let Some(dir) = save_dir {
+        println!("Saving rendered pages to: {}", dir);
+    }
+
+    let mut any_backend = false;
+
+    #[cfg(feature = "pdfium")]
+    {
+        any_backend = true;
+        match pdfium_backend::render(path, dpi, save_dir, password) {
+            Ok(stats) => render::report("pdfium-render", &stats),
+            Err(e) => println!("Backend: pdfium-render (render)\n  Error: {}", e),
+        }
+    }
+
+    #[cfg(all(feature = "poppler", feature = "render"))]
+    {
+        any_backend = true;
+        match poppler_backend::render(path, dpi, save_dir, password) {
+            Ok(stats) => render::report("poppler-rs", &stats),
+            Err(e) => println!("Backend: poppler-rs (render)\n  Error: {}", e),
+        }
+    }
+
+    if !any_backend {
+        println!("No render-capable backend compiled in (enable the `pdfium` feature, or `poppler` + `render`).");
+    }
 
     println!("Done.");
 }